@@ -0,0 +1,219 @@
+//! Maps raw buttons and controller axes to named actions and axes, with
+//! runtime rebinding.
+
+use std::collections::HashMap;
+
+use { Button, ControllerAxisEvent, GenericEvent, PressEvent, ReleaseEvent };
+use input_state::InputState;
+
+/// The name of a bound action or axis.
+pub type ActionId = String;
+
+/// Describes how an analog axis value is produced from raw input.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AxisBinding {
+    /// Combines two buttons into a -1.0/0.0/1.0 axis.
+    Keys {
+        /// Button that drives the axis towards `1.0`.
+        positive: Button,
+        /// Button that drives the axis towards `-1.0`.
+        negative: Button,
+    },
+    /// Reads a controller axis directly, clamped to `[-1.0, 1.0]` and
+    /// deadzoned around `0.0`.
+    Controller {
+        /// Which controller.
+        id: i32,
+        /// Which axis on the controller.
+        axis: u8,
+        /// Positions within this distance of zero are reported as `0.0`.
+        deadzone: f64,
+    },
+}
+
+/// An action or axis event produced by resolving raw input through
+/// `Bindings`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum BoundEvent {
+    /// A bound action was pressed.
+    ActionPress(ActionId),
+    /// A bound action was released.
+    ActionRelease(ActionId),
+    /// A bound axis changed value.
+    AxisChanged(ActionId, f64),
+}
+
+/// Maps raw buttons and controller axes to user-named actions and axes.
+///
+/// `insert_axis` replaces any axis binding of the same name. `insert_action`
+/// adds a button to an action; buttons already bound to that action stay
+/// bound.
+#[derive(Clone, Debug)]
+pub struct Bindings {
+    actions: HashMap<Button, Vec<ActionId>>,
+    axes: HashMap<ActionId, AxisBinding>,
+}
+
+impl Bindings {
+    /// Creates an empty set of bindings.
+    pub fn new() -> Bindings {
+        Bindings {
+            actions: HashMap::new(),
+            axes: HashMap::new(),
+        }
+    }
+
+    /// Binds `button` to the named digital action.
+    pub fn insert_action(&mut self, name: ActionId, button: Button) {
+        self.actions.entry(button).or_insert_with(Vec::new).push(name);
+    }
+
+    /// Binds an analog axis under `name`, replacing any existing binding of
+    /// the same name.
+    pub fn insert_axis(&mut self, name: ActionId, binding: AxisBinding) {
+        self.axes.insert(name, binding);
+    }
+
+    /// Resolves an incoming event into the bound events it triggers.
+    ///
+    /// `state` should already reflect `e`, i.e. call
+    /// `InputState::handle_event` before `resolve` for the same event.
+    pub fn resolve<E: GenericEvent>(&self, e: &E, state: &InputState) -> Vec<BoundEvent> {
+        let mut out = Vec::new();
+
+        if let Some(button) = e.press_args() {
+            if let Some(names) = self.actions.get(&button) {
+                out.extend(names.iter().cloned().map(BoundEvent::ActionPress));
+            }
+            self.push_axes_for_button(button, state, &mut out);
+        }
+        if let Some(button) = e.release_args() {
+            if let Some(names) = self.actions.get(&button) {
+                out.extend(names.iter().cloned().map(BoundEvent::ActionRelease));
+            }
+            self.push_axes_for_button(button, state, &mut out);
+        }
+        if let Some(args) = e.controller_axis_args() {
+            for (name, binding) in &self.axes {
+                if let AxisBinding::Controller { id, axis, .. } = *binding {
+                    if id == args.id && axis == args.axis {
+                        if let Some(value) = Self::resolve_axis(binding, state) {
+                            out.push(BoundEvent::AxisChanged(name.clone(), value));
+                        }
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    fn push_axes_for_button(&self, button: Button, state: &InputState, out: &mut Vec<BoundEvent>) {
+        for (name, binding) in &self.axes {
+            let uses_button = match *binding {
+                AxisBinding::Keys { positive, negative } =>
+                    button == positive || button == negative,
+                AxisBinding::Controller { .. } => false,
+            };
+            if uses_button {
+                if let Some(value) = Self::resolve_axis(binding, state) {
+                    out.push(BoundEvent::AxisChanged(name.clone(), value));
+                }
+            }
+        }
+    }
+
+    fn resolve_axis(binding: &AxisBinding, state: &InputState) -> Option<f64> {
+        match *binding {
+            AxisBinding::Keys { positive, negative } => {
+                let mut value = 0.0;
+                if state.pressed(positive) { value += 1.0; }
+                if state.pressed(negative) { value -= 1.0; }
+                Some(value)
+            }
+            AxisBinding::Controller { id, axis, deadzone } => {
+                state.axis_position(id, axis).map(|position| {
+                    if position.abs() < deadzone {
+                        0.0
+                    } else {
+                        position.max(-1.0).min(1.0)
+                    }
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{ Button, ControllerAxisArgs, ControllerButton, Input, Key, Motion };
+    use input_state::InputState;
+
+    #[test]
+    fn test_action_press_and_release() {
+        let mut bindings = Bindings::new();
+        let jump = Button::Keyboard(Key::Space);
+        bindings.insert_action("jump".to_string(), jump);
+
+        let mut state = InputState::new();
+        state.handle_event(&Input::Press(jump));
+        let pressed = bindings.resolve(&Input::Press(jump), &state);
+        assert_eq!(pressed, vec![BoundEvent::ActionPress("jump".to_string())]);
+
+        state.handle_event(&Input::Release(jump));
+        let released = bindings.resolve(&Input::Release(jump), &state);
+        assert_eq!(released, vec![BoundEvent::ActionRelease("jump".to_string())]);
+    }
+
+    #[test]
+    fn test_key_axis_combines_positive_and_negative() {
+        let mut bindings = Bindings::new();
+        let right = Button::Keyboard(Key::Right);
+        let left = Button::Keyboard(Key::Left);
+        bindings.insert_axis("move".to_string(), AxisBinding::Keys {
+            positive: right,
+            negative: left,
+        });
+
+        let mut state = InputState::new();
+        state.handle_event(&Input::Press(right));
+        let out = bindings.resolve(&Input::Press(right), &state);
+        assert_eq!(out, vec![BoundEvent::AxisChanged("move".to_string(), 1.0)]);
+
+        state.handle_event(&Input::Press(left));
+        let out = bindings.resolve(&Input::Press(left), &state);
+        assert_eq!(out, vec![BoundEvent::AxisChanged("move".to_string(), 0.0)]);
+    }
+
+    #[test]
+    fn test_controller_axis_deadzone_and_clamp() {
+        let mut bindings = Bindings::new();
+        bindings.insert_axis("aim".to_string(), AxisBinding::Controller {
+            id: 0,
+            axis: 1,
+            deadzone: 0.1,
+        });
+
+        let mut state = InputState::new();
+        let small = Input::Move(Motion::ControllerAxis(ControllerAxisArgs::new(0, 1, 0.05)));
+        state.handle_event(&small);
+        let out = bindings.resolve(&small, &state);
+        assert_eq!(out, vec![BoundEvent::AxisChanged("aim".to_string(), 0.0)]);
+
+        let large = Input::Move(Motion::ControllerAxis(ControllerAxisArgs::new(0, 1, 1.5)));
+        state.handle_event(&large);
+        let out = bindings.resolve(&large, &state);
+        assert_eq!(out, vec![BoundEvent::AxisChanged("aim".to_string(), 1.0)]);
+    }
+
+    #[test]
+    fn test_unbound_input_resolves_to_nothing() {
+        let bindings = Bindings::new();
+        let state = InputState::new();
+        let button = Button::Controller(ControllerButton::new(0, 0));
+
+        let out = bindings.resolve(&Input::Press(button), &state);
+        assert!(out.is_empty());
+    }
+}
@@ -132,6 +132,192 @@ impl<I: ControllerAxisEvent> ControllerAxisEvent for Event<I> {
     }
 }
 
+/// The state of a controller hat switch (D-pad).
+#[derive(Copy, Clone, RustcDecodable, RustcEncodable, PartialEq, Eq, Debug, Hash)]
+pub enum HatState {
+    /// Not pressed in any direction.
+    Centered,
+    /// Up.
+    Up,
+    /// Up and right.
+    RightUp,
+    /// Right.
+    Right,
+    /// Down and right.
+    RightDown,
+    /// Down.
+    Down,
+    /// Down and left.
+    LeftDown,
+    /// Left.
+    Left,
+    /// Up and left.
+    LeftUp,
+}
+
+/// Components of a controller hat (D-pad) event. Not guaranteed consistent
+/// across backends.
+#[derive(Copy, Clone, RustcDecodable, RustcEncodable, PartialEq, Eq, Debug, Hash)]
+pub struct ControllerHatArgs {
+    /// Which controller the hat is on.
+    pub id: i32,
+    /// Which hat switch on the controller.
+    pub which: u8,
+    /// The direction the hat switch is pressed in.
+    pub state: HatState,
+}
+
+impl ControllerHatArgs {
+    /// Create a new ControllerHatArgs object. Intended for use by backends
+    /// when emitting events.
+    pub fn new(id: i32, which: u8, state: HatState) -> Self {
+        ControllerHatArgs {
+            id: id,
+            which: which,
+            state: state,
+        }
+    }
+}
+
+/// The state of a controller hat switch (D-pad) changed.
+pub trait ControllerHatEvent: Sized {
+    /// Creates a controller hat event.
+    fn from_controller_hat_args(
+        args: ControllerHatArgs,
+        old_event: &Self
+    ) -> Option<Self>;
+    /// Calls closure if this is a controller hat event.
+    fn controller_hat<U, F>(&self, f: F) -> Option<U>
+        where F: FnMut(ControllerHatArgs) -> U;
+    /// Returns controller hat arguments.
+    fn controller_hat_args(&self) -> Option<ControllerHatArgs> {
+        self.controller_hat(|args| args)
+    }
+}
+
+impl ControllerHatEvent for Input {
+    fn from_controller_hat_args(
+        args: ControllerHatArgs,
+        _old_event: &Self
+    ) -> Option<Self> {
+        Some(Input::Move(Motion::ControllerHat(args)))
+    }
+
+    fn controller_hat<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(ControllerHatArgs) -> U
+    {
+        match *self {
+            Input::Move(Motion::ControllerHat(args)) => Some(f(args)),
+            _ => None
+        }
+    }
+}
+
+impl<I: ControllerHatEvent> ControllerHatEvent for Event<I> {
+    fn from_controller_hat_args(
+        args: ControllerHatArgs,
+        old_event: &Self
+    ) -> Option<Self> {
+        if let &Event::Input(ref old_input) = old_event {
+            <I as ControllerHatEvent>::from_controller_hat_args(args, old_input)
+                .map(|x| Event::Input(x))
+        } else {
+            None
+        }
+    }
+
+    fn controller_hat<U, F>(&self, f: F) -> Option<U>
+        where F: FnMut(ControllerHatArgs) -> U
+    {
+        match *self {
+            Event::Input(ref x) => x.controller_hat(f),
+            _ => None
+        }
+    }
+}
+
+/// Components of a controller connect/disconnect event. Not guaranteed
+/// consistent across backends.
+#[derive(Clone, RustcDecodable, RustcEncodable, PartialEq, Debug)]
+pub struct ControllerConnectionArgs {
+    /// Which controller connected or disconnected.
+    pub id: i32,
+    /// `true` if the controller was connected, `false` if disconnected.
+    pub connected: bool,
+    /// The name of the controller, if the backend can report one.
+    pub name: Option<String>,
+}
+
+impl ControllerConnectionArgs {
+    /// Create a new ControllerConnectionArgs object. Intended for use by
+    /// backends when emitting events.
+    pub fn new(id: i32, connected: bool, name: Option<String>) -> Self {
+        ControllerConnectionArgs {
+            id: id,
+            connected: connected,
+            name: name,
+        }
+    }
+}
+
+/// A controller was connected or disconnected.
+pub trait ControllerConnectionEvent: Sized {
+    /// Creates a controller connection event.
+    fn from_controller_connection_args(
+        args: ControllerConnectionArgs,
+        old_event: &Self
+    ) -> Option<Self>;
+    /// Calls closure if this is a controller connection event.
+    fn controller_connection<U, F>(&self, f: F) -> Option<U>
+        where F: FnMut(ControllerConnectionArgs) -> U;
+    /// Returns controller connection arguments.
+    fn controller_connection_args(&self) -> Option<ControllerConnectionArgs> {
+        self.controller_connection(|args| args)
+    }
+}
+
+impl ControllerConnectionEvent for Input {
+    fn from_controller_connection_args(
+        args: ControllerConnectionArgs,
+        _old_event: &Self
+    ) -> Option<Self> {
+        Some(Input::Controller(args))
+    }
+
+    fn controller_connection<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(ControllerConnectionArgs) -> U
+    {
+        match *self {
+            Input::Controller(ref args) => Some(f(args.clone())),
+            _ => None
+        }
+    }
+}
+
+impl<I: ControllerConnectionEvent> ControllerConnectionEvent for Event<I> {
+    fn from_controller_connection_args(
+        args: ControllerConnectionArgs,
+        old_event: &Self
+    ) -> Option<Self> {
+        if let &Event::Input(ref old_input) = old_event {
+            <I as ControllerConnectionEvent>::from_controller_connection_args(
+                args, old_input)
+                .map(|x| Event::Input(x))
+        } else {
+            None
+        }
+    }
+
+    fn controller_connection<U, F>(&self, f: F) -> Option<U>
+        where F: FnMut(ControllerConnectionArgs) -> U
+    {
+        match *self {
+            Event::Input(ref x) => x.controller_connection(f),
+            _ => None
+        }
+    }
+}
+
 #[cfg(test)]
 mod controller_axis_tests {
     use super::*;
@@ -167,3 +353,72 @@ mod controller_axis_tests {
         assert_eq!(a, b);
     }
 }
+
+#[cfg(test)]
+mod controller_hat_tests {
+    use super::*;
+
+    #[test]
+    fn test_input_controller_hat() {
+        use super::super::{ Input, Motion };
+
+        let e = Input::Move(Motion::ControllerHat(
+            ControllerHatArgs::new(0, 1, HatState::Up)));
+        let a: Option<Input> = ControllerHatEvent::from_controller_hat_args(
+            ControllerHatArgs::new(0, 1, HatState::Up), &e);
+        let b: Option<Input> = a.clone().unwrap().controller_hat(|hat|
+            ControllerHatEvent::from_controller_hat_args(
+                ControllerHatArgs::new(hat.id, hat.which, hat.state),
+                a.as_ref().unwrap())).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_event_controller_hat() {
+        use Event;
+        use super::super::{ Input, Motion };
+
+        let e = Event::Input(Input::Move(Motion::ControllerHat(
+            ControllerHatArgs::new(0, 1, HatState::Up))));
+        let a: Option<Event> = ControllerHatEvent::from_controller_hat_args(
+            ControllerHatArgs::new(0, 1, HatState::Up), &e);
+        let b: Option<Event> = a.clone().unwrap().controller_hat(|hat|
+            ControllerHatEvent::from_controller_hat_args(
+                ControllerHatArgs::new(hat.id, hat.which, hat.state),
+                a.as_ref().unwrap())).unwrap();
+        assert_eq!(a, b);
+    }
+}
+
+#[cfg(test)]
+mod controller_connection_tests {
+    use super::*;
+
+    #[test]
+    fn test_input_controller_connection() {
+        use super::super::Input;
+
+        let e = Input::Controller(ControllerConnectionArgs::new(0, true, None));
+        let a: Option<Input> = ControllerConnectionEvent::from_controller_connection_args(
+            ControllerConnectionArgs::new(0, true, None), &e);
+        let b: Option<Input> = a.clone().unwrap().controller_connection(|args|
+            ControllerConnectionEvent::from_controller_connection_args(
+                args, a.as_ref().unwrap())).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_event_controller_connection() {
+        use Event;
+        use super::super::Input;
+
+        let e = Event::Input(Input::Controller(
+            ControllerConnectionArgs::new(0, true, None)));
+        let a: Option<Event> = ControllerConnectionEvent::from_controller_connection_args(
+            ControllerConnectionArgs::new(0, true, None), &e);
+        let b: Option<Event> = a.clone().unwrap().controller_connection(|args|
+            ControllerConnectionEvent::from_controller_connection_args(
+                args, a.as_ref().unwrap())).unwrap();
+        assert_eq!(a, b);
+    }
+}
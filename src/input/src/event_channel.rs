@@ -0,0 +1,149 @@
+//! A double-buffered event channel with independently-paced readers.
+
+use std::iter::Chain;
+use std::slice;
+
+/// A cursor into an `EventChannel`, handed out by `register_reader`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ReaderId {
+    generation: u64,
+    index: usize,
+}
+
+/// A queue of events kept in two buffers that swap on each `flush`,
+/// retaining events for exactly two frames so a reader that runs once per
+/// frame never misses one.
+pub struct EventChannel<E> {
+    buffers: [Vec<E>; 2],
+    active: usize,
+    generation: u64,
+}
+
+impl<E> EventChannel<E> {
+    /// Creates an empty channel.
+    pub fn new() -> EventChannel<E> {
+        EventChannel {
+            buffers: [Vec::new(), Vec::new()],
+            active: 0,
+            generation: 0,
+        }
+    }
+
+    /// Appends an event to the active buffer.
+    pub fn write(&mut self, e: E) {
+        self.buffers[self.active].push(e);
+    }
+
+    /// Registers a new reader, which starts from the next event written.
+    pub fn register_reader(&mut self) -> ReaderId {
+        ReaderId {
+            generation: self.generation,
+            index: self.buffers[self.active].len(),
+        }
+    }
+
+    /// Swaps the active buffer, clearing the one that has aged out of the
+    /// two-frame retention window. Call once per frame.
+    pub fn flush(&mut self) {
+        let next = 1 - self.active;
+        self.buffers[next].clear();
+        self.active = next;
+        self.generation += 1;
+    }
+
+    /// Returns every event written since `reader` last read, advancing its
+    /// cursor past the current buffer swap.
+    ///
+    /// If `reader` is more than one `flush` behind, only the events still in
+    /// the two buffers are returned; events cleared by `flush` are lost.
+    pub fn read<'a>(&'a self, reader: &mut ReaderId) -> Chain<slice::Iter<'a, E>, slice::Iter<'a, E>> {
+        let age = self.generation - reader.generation;
+        let stale = 1 - self.active;
+
+        let (first, second): (&[E], &[E]) = if age == 0 {
+            (&self.buffers[self.active][reader.index..], &[])
+        } else if age == 1 {
+            (&self.buffers[stale][reader.index..], &self.buffers[self.active][..])
+        } else {
+            // The reader is more than one flush behind. Its stored index
+            // belongs to a buffer generation that has since been recycled,
+            // so it can't be used to slice into either surviving buffer.
+            // Everything still resident (the previous and current
+            // generations) hasn't been delivered yet, so replay all of it;
+            // only events older than that were already overwritten.
+            (&self.buffers[stale][..], &self.buffers[self.active][..])
+        };
+
+        reader.generation = self.generation;
+        reader.index = self.buffers[self.active].len();
+        first.iter().chain(second.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_same_generation_returns_new_events_only() {
+        let mut channel: EventChannel<i32> = EventChannel::new();
+        let mut reader = channel.register_reader();
+
+        channel.write(1);
+        channel.write(2);
+
+        assert_eq!(channel.read(&mut reader).collect::<Vec<_>>(), vec![&1, &2]);
+        assert_eq!(channel.read(&mut reader).collect::<Vec<_>>(), Vec::<&i32>::new());
+    }
+
+    #[test]
+    fn test_read_across_one_flush_sees_both_generations() {
+        let mut channel: EventChannel<i32> = EventChannel::new();
+        let mut reader = channel.register_reader();
+
+        channel.write(1);
+        channel.flush();
+        channel.write(2);
+
+        assert_eq!(channel.read(&mut reader).collect::<Vec<_>>(), vec![&1, &2]);
+    }
+
+    #[test]
+    fn test_read_after_two_flushes_still_sees_resident_events() {
+        let mut channel: EventChannel<i32> = EventChannel::new();
+        let mut reader = channel.register_reader();
+
+        channel.flush();
+        channel.flush();
+        channel.write(5);
+
+        assert_eq!(channel.read(&mut reader).collect::<Vec<_>>(), vec![&5]);
+    }
+
+    #[test]
+    fn test_read_does_not_duplicate_events_across_registration_points() {
+        let mut channel: EventChannel<i32> = EventChannel::new();
+
+        channel.write(1);
+        let mut reader = channel.register_reader();
+        channel.write(2);
+
+        assert_eq!(channel.read(&mut reader).collect::<Vec<_>>(), vec![&2]);
+    }
+
+    #[test]
+    fn test_two_readers_advance_independently() {
+        let mut channel: EventChannel<i32> = EventChannel::new();
+        let mut fast_reader = channel.register_reader();
+        let mut slow_reader = channel.register_reader();
+
+        channel.write(1);
+        assert_eq!(channel.read(&mut fast_reader).collect::<Vec<_>>(), vec![&1]);
+
+        channel.flush();
+        channel.write(2);
+
+        assert_eq!(channel.read(&mut slow_reader).collect::<Vec<_>>(), vec![&1, &2]);
+        assert_eq!(channel.read(&mut fast_reader).collect::<Vec<_>>(), vec![&2]);
+    }
+}
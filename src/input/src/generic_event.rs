@@ -3,19 +3,23 @@
 use std::borrow::ToOwned;
 use std::any::Any;
 
-use {AfterRenderEvent, ControllerAxisEvent, CursorEvent, FocusEvent, IdleEvent,
+use {AfterRenderEvent, ControllerAxisEvent, ControllerConnectionEvent, ControllerHatEvent,
+     CursorEvent, FocusEvent, IdleEvent,
      MouseCursorEvent, MouseRelativeEvent, MouseScrollEvent,
      PressEvent, ReleaseEvent, RenderEvent, ResizeEvent,
      TextEvent, TouchEvent, UpdateEvent};
-use {AfterRenderArgs, ControllerAxisArgs, Button, Event, EventId, IdleArgs, Input,
+use {AfterRenderArgs, ControllerAxisArgs, ControllerConnectionArgs, ControllerHatArgs,
+     Button, Event, EventId, IdleArgs, Input,
      Motion, RenderArgs, TouchArgs, UpdateArgs};
-use {AFTER_RENDER, CONTROLLER_AXIS, CURSOR, FOCUS, CLOSE, IDLE, MOUSE_CURSOR,
+use {AFTER_RENDER, CONTROLLER_AXIS, CONTROLLER_CONNECT, CONTROLLER_HAT, CURSOR, FOCUS,
+     CLOSE, IDLE, MOUSE_CURSOR,
      MOUSE_RELATIVE, MOUSE_SCROLL, PRESS, RENDER, RELEASE, RESIZE,
      TEXT, TOUCH, UPDATE};
 
 /// Implemented by all events
 pub trait GenericEvent: Sized +
-    AfterRenderEvent + ControllerAxisEvent + CursorEvent + FocusEvent + IdleEvent +
+    AfterRenderEvent + ControllerAxisEvent + ControllerConnectionEvent + ControllerHatEvent +
+    CursorEvent + FocusEvent + IdleEvent +
     MouseCursorEvent + MouseRelativeEvent + MouseScrollEvent +
     PressEvent + ReleaseEvent + RenderEvent + ResizeEvent +
     TextEvent + TouchEvent + UpdateEvent {
@@ -41,11 +45,13 @@ impl GenericEvent for Input {
             &Input::Move(Motion::MouseRelative(_, _)) => MOUSE_RELATIVE,
             &Input::Move(Motion::MouseScroll(_, _)) => MOUSE_SCROLL,
             &Input::Move(Motion::ControllerAxis(_)) => CONTROLLER_AXIS,
+            &Input::Move(Motion::ControllerHat(_)) => CONTROLLER_HAT,
             &Input::Move(Motion::Touch(_)) => TOUCH,
             &Input::Press(_) => PRESS,
             &Input::Release(_) => RELEASE,
             &Input::Resize(_, _) => RESIZE,
             &Input::Text(_) => TEXT,
+            &Input::Controller(_) => CONTROLLER_CONNECT,
         }
     }
 
@@ -61,6 +67,8 @@ impl GenericEvent for Input {
                 f(&(None as Option<()>)),
             &Input::Move(Motion::ControllerAxis(args)) =>
                 f(&args as &Any),
+            &Input::Move(Motion::ControllerHat(args)) =>
+                f(&args as &Any),
             &Input::Move(Motion::MouseCursor(x, y)) =>
                 f(&(x, y) as &Any),
             &Input::Move(Motion::MouseRelative(x, y)) =>
@@ -77,6 +85,8 @@ impl GenericEvent for Input {
                 f(&(w, h) as &Any),
             &Input::Text(ref text) =>
                 f(text as &Any),
+            &Input::Controller(ref args) =>
+                f(args as &Any),
         }
     }
 
@@ -89,6 +99,20 @@ impl GenericEvent for Input {
                     panic!("Expected ControllerAxisArgs")
                 }
             }
+            x if x == CONTROLLER_HAT => {
+                if let Some(&args) = any.downcast_ref::<ControllerHatArgs>() {
+                    Some(Input::Move(Motion::ControllerHat(args)))
+                } else {
+                    panic!("Expected ControllerHatArgs")
+                }
+            }
+            x if x == CONTROLLER_CONNECT => {
+                if let Some(args) = any.downcast_ref::<ControllerConnectionArgs>() {
+                    Some(Input::Controller(args.clone()))
+                } else {
+                    panic!("Expected ControllerConnectionArgs")
+                }
+            }
             x if x == CURSOR => {
                 if let Some(&cursor) = any.downcast_ref::<bool>() {
                     Some(Input::Cursor(cursor))
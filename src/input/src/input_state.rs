@@ -0,0 +1,177 @@
+//! Tracks the current state of buttons and controller axes.
+
+use std::collections::hash_set;
+use std::collections::{ HashMap, HashSet };
+
+use { Button, ControllerAxisEvent, ControllerButton, ControllerConnectionEvent, GenericEvent,
+      PressEvent, ReleaseEvent };
+
+/// Tracks button and controller axis state by observing every event fed
+/// through `handle_event`.
+///
+/// `pressed`, `just_pressed` and `just_released` are O(1) lookups. Call
+/// `clear` once per `UpdateEvent`/`AfterRenderEvent` so `just_pressed`/
+/// `just_released` line up with a single frame.
+#[derive(Clone, Debug)]
+pub struct InputState {
+    pressed: HashSet<Button>,
+    just_pressed: HashSet<Button>,
+    just_released: HashSet<Button>,
+    axes: HashMap<(i32, u8), f64>,
+    controller_buttons: HashMap<(i32, u8), bool>,
+}
+
+impl InputState {
+    /// Creates a new, empty `InputState`.
+    pub fn new() -> InputState {
+        InputState {
+            pressed: HashSet::new(),
+            just_pressed: HashSet::new(),
+            just_released: HashSet::new(),
+            axes: HashMap::new(),
+            controller_buttons: HashMap::new(),
+        }
+    }
+
+    /// Feeds an event through the tracker, updating the current state.
+    pub fn handle_event<E: GenericEvent>(&mut self, e: &E) {
+        if let Some(button) = e.press_args() {
+            if self.pressed.insert(button) {
+                self.just_pressed.insert(button);
+            }
+            if let Button::Controller(ControllerButton { id, button }) = button {
+                self.controller_buttons.insert((id, button), true);
+            }
+        }
+        if let Some(button) = e.release_args() {
+            self.pressed.remove(&button);
+            self.just_released.insert(button);
+            if let Button::Controller(ControllerButton { id, button }) = button {
+                self.controller_buttons.insert((id, button), false);
+            }
+        }
+        if let Some(args) = e.controller_axis_args() {
+            self.axes.insert((args.id, args.axis), args.position);
+        }
+        if let Some(args) = e.controller_connection_args() {
+            if !args.connected {
+                self.axes.retain(|&(id, _), _| id != args.id);
+                self.controller_buttons.retain(|&(id, _), _| id != args.id);
+            }
+        }
+    }
+
+    /// Returns `true` if `button` is currently held down.
+    pub fn pressed(&self, button: Button) -> bool {
+        self.pressed.contains(&button)
+    }
+
+    /// Returns `true` if `button` was pressed since the last `clear`.
+    pub fn just_pressed(&self, button: Button) -> bool {
+        self.just_pressed.contains(&button)
+    }
+
+    /// Returns `true` if `button` was released since the last `clear`.
+    pub fn just_released(&self, button: Button) -> bool {
+        self.just_released.contains(&button)
+    }
+
+    /// Returns `true` if the controller button is currently held down.
+    pub fn controller_button(&self, id: i32, button: u8) -> bool {
+        self.controller_buttons.get(&(id, button)).cloned().unwrap_or(false)
+    }
+
+    /// Returns the last known position of a controller axis.
+    pub fn axis_position(&self, id: i32, axis: u8) -> Option<f64> {
+        self.axes.get(&(id, axis)).cloned()
+    }
+
+    /// Returns an iterator over the buttons that are currently held down.
+    pub fn get_pressed(&self) -> hash_set::Iter<Button> {
+        self.pressed.iter()
+    }
+
+    /// Clears the per-frame edge-triggered state, leaving `pressed` intact.
+    ///
+    /// Call this once per `UpdateEvent`/`AfterRenderEvent` so that
+    /// `just_pressed`/`just_released` queries line up with a frame boundary.
+    pub fn clear(&mut self) {
+        self.just_pressed.clear();
+        self.just_released.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{ ControllerAxisArgs, ControllerButton, ControllerConnectionArgs,
+                         Input, Motion };
+
+    fn controller_button(id: i32, button: u8) -> Button {
+        Button::Controller(ControllerButton::new(id, button))
+    }
+
+    #[test]
+    fn test_press_sets_pressed_and_just_pressed() {
+        let mut state = InputState::new();
+        let button = controller_button(0, 1);
+
+        state.handle_event(&Input::Press(button));
+
+        assert!(state.pressed(button));
+        assert!(state.just_pressed(button));
+        assert!(!state.just_released(button));
+        assert!(state.controller_button(0, 1));
+    }
+
+    #[test]
+    fn test_clear_keeps_pressed_but_drops_edges() {
+        let mut state = InputState::new();
+        let button = controller_button(0, 1);
+
+        state.handle_event(&Input::Press(button));
+        state.clear();
+
+        assert!(state.pressed(button));
+        assert!(!state.just_pressed(button));
+    }
+
+    #[test]
+    fn test_release_clears_pressed_and_sets_just_released() {
+        let mut state = InputState::new();
+        let button = controller_button(0, 1);
+
+        state.handle_event(&Input::Press(button));
+        state.clear();
+        state.handle_event(&Input::Release(button));
+
+        assert!(!state.pressed(button));
+        assert!(state.just_released(button));
+        assert!(!state.controller_button(0, 1));
+    }
+
+    #[test]
+    fn test_controller_axis_tracks_position() {
+        let mut state = InputState::new();
+
+        state.handle_event(&Input::Move(Motion::ControllerAxis(
+            ControllerAxisArgs::new(0, 2, 0.5))));
+
+        assert_eq!(state.axis_position(0, 2), Some(0.5));
+    }
+
+    #[test]
+    fn test_disconnect_drops_controller_state() {
+        let mut state = InputState::new();
+        let button = controller_button(0, 1);
+
+        state.handle_event(&Input::Press(button));
+        state.handle_event(&Input::Move(Motion::ControllerAxis(
+            ControllerAxisArgs::new(0, 2, 0.5))));
+        state.handle_event(&Input::Controller(
+            ControllerConnectionArgs::new(0, false, None)));
+
+        assert!(!state.controller_button(0, 1));
+        assert_eq!(state.axis_position(0, 2), None);
+    }
+}
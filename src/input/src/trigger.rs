@@ -0,0 +1,181 @@
+//! Event-matching triggers and a consumable handler chain.
+
+use std::any::Any;
+
+use { Button, EventId, GenericEvent, PRESS, RELEASE };
+
+/// Matches events against a predicate, without needing to know the concrete
+/// event type ahead of time.
+pub struct EventTrigger {
+    f: Box<Fn(&Any, EventId) -> bool>,
+}
+
+impl EventTrigger {
+    /// Creates a trigger that fires on any event with the given `EventId`.
+    pub fn from_event_id(id: EventId) -> EventTrigger {
+        EventTrigger {
+            f: Box::new(move |_, event_id| event_id == id),
+        }
+    }
+
+    /// Creates a trigger that fires when `button` is pressed.
+    pub fn press(button: Button) -> EventTrigger {
+        EventTrigger {
+            f: Box::new(move |any, event_id| {
+                event_id == PRESS &&
+                any.downcast_ref::<Button>().map_or(false, |&b| b == button)
+            }),
+        }
+    }
+
+    /// Creates a trigger that fires when `button` is released.
+    pub fn release(button: Button) -> EventTrigger {
+        EventTrigger {
+            f: Box::new(move |any, event_id| {
+                event_id == RELEASE &&
+                any.downcast_ref::<Button>().map_or(false, |&b| b == button)
+            }),
+        }
+    }
+
+    /// Creates a trigger that fires if any of `triggers` fires.
+    pub fn any_of(triggers: Vec<EventTrigger>) -> EventTrigger {
+        EventTrigger {
+            f: Box::new(move |any, event_id| {
+                triggers.iter().any(|trigger| (trigger.f)(any, event_id))
+            }),
+        }
+    }
+
+    /// Returns `true` if this trigger fires on `e`.
+    pub fn fires_on<E: GenericEvent>(&self, e: &E) -> bool {
+        let event_id = e.event_id();
+        e.with_args(|any| (self.f)(any, event_id))
+    }
+}
+
+/// Whether a handler consumed an event or let it fall through.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EventResult {
+    /// The event was handled; stop walking the handler chain.
+    Consumed,
+    /// The event was not handled; pass it to the next handler.
+    Ignored,
+}
+
+/// An ordered chain of `(EventTrigger, handler)` pairs, walked until a
+/// handler consumes the event.
+pub struct Dispatcher<E> {
+    handlers: Vec<(EventTrigger, Box<FnMut(&E) -> EventResult>)>,
+}
+
+impl<E: GenericEvent> Dispatcher<E> {
+    /// Creates an empty dispatcher.
+    pub fn new() -> Dispatcher<E> {
+        Dispatcher {
+            handlers: Vec::new(),
+        }
+    }
+
+    /// Appends a handler that runs when `trigger` fires on an event.
+    pub fn add<F>(&mut self, trigger: EventTrigger, handler: F)
+        where F: FnMut(&E) -> EventResult + 'static
+    {
+        self.handlers.push((trigger, Box::new(handler)));
+    }
+
+    /// Walks the handler chain in order, stopping at the first handler
+    /// whose trigger fires and that returns `EventResult::Consumed`.
+    pub fn dispatch(&mut self, e: &E) -> EventResult {
+        for &mut (ref trigger, ref mut handler) in &mut self.handlers {
+            if trigger.fires_on(e) {
+                if let EventResult::Consumed = handler(e) {
+                    return EventResult::Consumed;
+                }
+            }
+        }
+        EventResult::Ignored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{ Button, ControllerButton, Input };
+
+    fn controller_button(id: i32, button: u8) -> Button {
+        Button::Controller(ControllerButton::new(id, button))
+    }
+
+    #[test]
+    fn test_press_trigger_fires_only_for_its_button() {
+        let button = controller_button(0, 1);
+        let other = controller_button(0, 2);
+        let trigger = EventTrigger::press(button);
+
+        assert!(trigger.fires_on(&Input::Press(button)));
+        assert!(!trigger.fires_on(&Input::Press(other)));
+        assert!(!trigger.fires_on(&Input::Release(button)));
+    }
+
+    #[test]
+    fn test_any_of_fires_if_any_inner_trigger_fires() {
+        let a = controller_button(0, 1);
+        let b = controller_button(0, 2);
+        let c = controller_button(0, 3);
+        let trigger = EventTrigger::any_of(vec![
+            EventTrigger::press(a),
+            EventTrigger::press(b),
+        ]);
+
+        assert!(trigger.fires_on(&Input::Press(a)));
+        assert!(trigger.fires_on(&Input::Press(b)));
+        assert!(!trigger.fires_on(&Input::Press(c)));
+    }
+
+    #[test]
+    fn test_dispatch_stops_at_first_consuming_handler() {
+        let button = controller_button(0, 1);
+        let mut dispatcher: Dispatcher<Input> = Dispatcher::new();
+
+        let seen = ::std::rc::Rc::new(::std::cell::RefCell::new(Vec::new()));
+        let seen_in_first = seen.clone();
+        dispatcher.add(EventTrigger::press(button), move |_| {
+            seen_in_first.borrow_mut().push("first");
+            EventResult::Consumed
+        });
+        let seen_in_second = seen.clone();
+        dispatcher.add(EventTrigger::press(button), move |_| {
+            seen_in_second.borrow_mut().push("second");
+            EventResult::Consumed
+        });
+
+        let result = dispatcher.dispatch(&Input::Press(button));
+
+        assert_eq!(result, EventResult::Consumed);
+        assert_eq!(*seen.borrow(), vec!["first"]);
+    }
+
+    #[test]
+    fn test_dispatch_falls_through_to_next_when_ignored() {
+        let button = controller_button(0, 1);
+        let mut dispatcher: Dispatcher<Input> = Dispatcher::new();
+
+        dispatcher.add(EventTrigger::press(button), |_| EventResult::Ignored);
+        dispatcher.add(EventTrigger::press(button), |_| EventResult::Consumed);
+
+        let result = dispatcher.dispatch(&Input::Press(button));
+        assert_eq!(result, EventResult::Consumed);
+    }
+
+    #[test]
+    fn test_dispatch_ignores_when_no_trigger_fires() {
+        let button = controller_button(0, 1);
+        let mut dispatcher: Dispatcher<Input> = Dispatcher::new();
+
+        dispatcher.add(EventTrigger::press(button), |_| EventResult::Consumed);
+
+        let result = dispatcher.dispatch(&Input::Release(button));
+        assert_eq!(result, EventResult::Ignored);
+    }
+}